@@ -2,17 +2,78 @@
 
 /// When to use linear search instead of binary search
 const LINEAR_THRESH: usize = 32;
-/// Key Type
-type T = u32;
 /// Number of bits to use for radix
-const RADIX_BITS: T = 10;
+const RADIX_BITS: u32 = 10;
+/// Target number of spline points per radix bucket that `Builder::auto_radix` aims for, kept
+/// comfortably under `LINEAR_THRESH` so each bucket's linear scan stays fast.
+const AUTO_RADIX_TARGET: usize = LINEAR_THRESH / 2;
+/// Hard ceiling on requested radix bits, regardless of key width or caller input: beyond this
+/// the radix table itself (`2^bits` `usize` entries) would dwarf any realistic dataset, so
+/// `radix_table_for` clamps to this instead of trusting `Builder::with_radix_bits`/
+/// `auto_radix` callers not to ask for an unreasonably (or, on a full-width domain,
+/// unrepresentably) large table.
+const MAX_RADIX_BITS: u32 = 24;
 /// Precision to use for linear comparison
-const PREC: f32 = f32::EPSILON;
+const PREC: f64 = f64::EPSILON;
+
+/// A key type usable with [`Builder`]/[`RadixSpline`].
+///
+/// Keys are mapped into an unsigned `u64` offset domain (`self - min`) before any
+/// radix math happens, so `max - min` can never overflow even for signed keys, and
+/// the rest of the implementation only ever has to deal with one unsigned
+/// representation.
+pub trait Key: Copy + PartialOrd + Default + std::fmt::Debug {
+  /// `self - min` mapped into an unsigned offset. Assumes `self >= min`.
+  fn offset(self, min: Self) -> u64;
+
+  /// Byte width of this key's little-endian encoding.
+  const BYTES: usize;
+  /// Appends this key's little-endian bytes to `out`.
+  fn write_le(self, out: &mut Vec<u8>);
+  /// Reads a little-endian-encoded key from the front of `bytes`.
+  ///
+  /// `bytes` must be exactly [`Key::BYTES`] long.
+  fn read_le(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_key_unsigned {
+  ($($t:ty),*) => {$(
+    impl Key for $t {
+      fn offset(self, min: Self) -> u64 { (self - min) as u64 }
+
+      const BYTES: usize = std::mem::size_of::<$t>();
+      fn write_le(self, out: &mut Vec<u8>) { out.extend_from_slice(&self.to_le_bytes()); }
+      fn read_le(bytes: &[u8]) -> Self { Self::from_le_bytes(bytes.try_into().unwrap()) }
+    }
+  )*}
+}
+impl_key_unsigned!(u32, u64);
+
+macro_rules! impl_key_signed {
+  ($($t:ty, $u:ty, $sign_bit:expr),*) => {$(
+    impl Key for $t {
+      fn offset(self, min: Self) -> u64 {
+        // Flip the sign bit so the unsigned bit-pattern is order-preserving,
+        // then subtract in that domain: this can't overflow since min <= self.
+        let to_unsigned = |v: $t| (v as $u) ^ $sign_bit;
+        to_unsigned(self).wrapping_sub(to_unsigned(min)) as u64
+      }
+
+      const BYTES: usize = std::mem::size_of::<$t>();
+      fn write_le(self, out: &mut Vec<u8>) { out.extend_from_slice(&self.to_le_bytes()); }
+      fn read_le(bytes: &[u8]) -> Self { Self::from_le_bytes(bytes.try_into().unwrap()) }
+    }
+  )*}
+}
+impl_key_signed!(i32, u32, 1u32 << 31, i64, u64, 1u64 << 63);
 
+// `repr(C)` gives this a fixed, deterministic layout so `RadixSpline::to_bytes`/`view` can
+// reinterpret `[Coordinate<K>]` directly as raw bytes without a per-element conversion.
+#[repr(C)]
 #[derive(Clone, Copy, Debug, Default)]
-struct Coordinate {
-  x: T,
-  y: f32,
+struct Coordinate<K> {
+  x: K,
+  y: f64,
 }
 
 /// Represents the oreintation between two intervals
@@ -24,7 +85,7 @@ enum Orientation {
 }
 
 
-fn orient(dx1: f32, dy1: f32, dx2: f32, dy2: f32) -> Orientation {
+fn orient(dx1: f64, dy1: f64, dx2: f64, dy2: f64) -> Orientation {
   let e = (dy1 * dx2) - (dy2 * dx1);
   if e > PREC {
     Orientation::Clockwise
@@ -35,64 +96,116 @@ fn orient(dx1: f32, dy1: f32, dx2: f32, dy2: f32) -> Orientation {
   }
 }
 
-fn shift_bits(diff: T, radix_bits: T) -> T {
-  return (std::mem::size_of::<T>() as T)
+fn shift_bits<K>(diff: u64, radix_bits: u32) -> u32 {
+  let key_bits = std::mem::size_of::<K>() as u32 * 8;
+  // `diff` always fits in `key_bits` bits, but is widened to `u64`, so its leading zero count
+  // needs to drop the always-zero high bits contributed by that widening before it's
+  // comparable to `key_bits`.
+  let leading_zeros = diff.leading_zeros().saturating_sub(64 - key_bits);
+  let shift = key_bits
     .saturating_sub(radix_bits)
-    .saturating_sub(diff.leading_zeros() as T);
+    .saturating_sub(leading_zeros);
+  // `diff` is shifted as a `u64` regardless of `K`, so the shift amount must stay below 64
+  // (e.g. `radix_bits == 0` on a full-width `u64` domain would otherwise compute `shift ==
+  // key_bits == 64`, which overflows the shift).
+  shift.min(63)
 }
 
-#[derive(Default, Debug)]
-pub struct Builder {
-  min: T,
-  max: T,
+// Computes `shift_bits` and the zero-initialized radix table for a builder spanning
+// `[min, max]` at the given radix width, shared by `Builder::new` and
+// `Builder::with_radix_bits`.
+//
+// Requesting more radix bits than `K` is wide doesn't add precision (the shift already
+// saturates at 0 past that point), and requesting close to `K`'s full width on a domain that
+// itself spans close to the full `K` range drives `max_prefix` towards `diff` directly - up to
+// `u64::MAX` for a full-width `u64` domain, which both overflows the `+ 2` below and demands an
+// unallocatable radix table. Clamp `radix_bits` against both the key's bit width and
+// `MAX_RADIX_BITS` up front so `max_prefix` - and the table `radix_table_for` allocates - stays
+// bounded no matter what a caller passes in.
+fn radix_table_for<K: Key>(min: K, max: K, radix_bits: u32) -> (u32, Vec<usize>) {
+  let key_bits = std::mem::size_of::<K>() as u32 * 8;
+  let radix_bits = radix_bits.min(key_bits).min(MAX_RADIX_BITS);
+  let diff = max.offset(min);
+  let shift_bits = shift_bits::<K>(diff, radix_bits);
+  let max_prefix = diff >> shift_bits;
+  (shift_bits, vec![0; (max_prefix as usize).saturating_add(2)])
+}
+
+#[derive(Debug, Default)]
+pub struct Builder<K> {
+  min: K,
+  max: K,
 
-  shift_bits: T,
+  shift_bits: u32,
 
-  max_error: f32,
+  max_error: f64,
   prev_prefix: usize,
 
   radix_table: Vec<usize>,
-  spline_points: Vec<Coordinate>,
+  spline_points: Vec<Coordinate<K>>,
 
   // have to record points since not all will be in spline points
   num_points: usize,
 
   // last y value
-  prev_y: f32,
+  prev_y: f64,
   // last x added
-  prev_x: T,
+  prev_x: K,
 
   // number of distinct values there are(is this one redundant?)
   distinct: usize,
 
-  lower_limit: Coordinate,
-  upper_limit: Coordinate,
-  prev_point: Coordinate,
+  lower_limit: Coordinate<K>,
+  upper_limit: Coordinate<K>,
+  prev_point: Coordinate<K>,
 }
 
-impl Builder {
-  pub fn new(min: T, max: T) -> Self {
+impl<K: Key> Builder<K> {
+  pub fn new(min: K, max: K) -> Self {
     if min > max {
       return Self::new(max, min)
     }
-    let shift_bits = shift_bits(max - min, RADIX_BITS);
-    let max_prefix = (max - min) >> shift_bits;
+    let (shift_bits, radix_table) = radix_table_for(min, max, RADIX_BITS);
     Builder {
       min,
       max,
       shift_bits,
-      radix_table: vec![0; 2 + (max_prefix as usize)],
+      radix_table,
       max_error: 32.,
       prev_x: min,
       ..Default::default()
     }
   }
-  pub fn with_error(&mut self, err: f32) -> &mut Self {
+  pub fn with_error(&mut self, err: f64) -> &mut Self {
     assert_eq!(self.num_points, 0, "Must assign error before adding items");
     self.max_error = err;
     self
   }
-  pub fn build(mut self) -> RadixSpline {
+  /// Sets the number of radix bits used to bucket keys, recomputing `shift_bits` and
+  /// resizing the radix table accordingly. Too few bits makes each bucket span many spline
+  /// points (slow search); too many bloats the radix table. `bits` is clamped to the key's bit
+  /// width and to [`MAX_RADIX_BITS`], so an excessive value doesn't demand an unreasonably (or,
+  /// on a full-width domain, unrepresentably) large table.
+  pub fn with_radix_bits(&mut self, bits: u32) -> &mut Self {
+    assert_eq!(self.num_points, 0, "Must set radix bits before adding items");
+    let (shift_bits, radix_table) = radix_table_for(self.min, self.max, bits);
+    self.shift_bits = shift_bits;
+    self.radix_table = radix_table;
+    self
+  }
+  /// Picks a number of radix bits so that, given `expected_keys` keys spread over this
+  /// builder's `[min, max]` span, each radix bucket holds roughly [`AUTO_RADIX_TARGET`]
+  /// spline points on average, comfortably under [`LINEAR_THRESH`].
+  pub fn auto_radix(&mut self, expected_keys: usize) -> &mut Self {
+    let buckets_wanted = (expected_keys / AUTO_RADIX_TARGET).max(1);
+    let bits = if buckets_wanted <= 1 {
+      0
+    } else {
+      usize::BITS - (buckets_wanted - 1).leading_zeros()
+    };
+    self.with_radix_bits(bits)
+  }
+  pub fn build(mut self) -> RadixSpline<K> {
     if self.num_points == 0 {
       return RadixSpline::default();
     }
@@ -126,7 +239,7 @@ impl Builder {
       max_error,
     }
   }
-  pub fn push(&mut self, x: T) -> &mut Self {
+  pub fn push(&mut self, x: K) -> &mut Self {
     let y = if self.num_points == 0 {
       0.
     } else {
@@ -141,7 +254,7 @@ impl Builder {
     self
   }
 
-  fn insert(&mut self, x: T, y: f32) -> &mut Self {
+  fn insert(&mut self, x: K, y: f64) -> &mut Self {
     debug_assert!(self.min <= x && x <= self.max);
 
     if self.num_points == 0 {
@@ -172,9 +285,9 @@ impl Builder {
     debug_assert!(self.upper_limit.x >= last.x);
     debug_assert!(self.lower_limit.x >= last.x);
     debug_assert!(x >= last.x);
-    let upper_limit_x_diff = (self.upper_limit.x as f32) - (last.x as f32);
-    let lower_limit_x_diff = (self.lower_limit.x as f32) - (last.x as f32);
-    let x_diff = (x - last.x) as f32;
+    let upper_limit_x_diff = self.upper_limit.x.offset(last.x) as f64;
+    let lower_limit_x_diff = self.lower_limit.x.offset(last.x) as f64;
+    let x_diff = x.offset(last.x) as f64;
 
     debug_assert!(self.upper_limit.y >= last.y);
     debug_assert!(y >= last.y);
@@ -212,23 +325,23 @@ impl Builder {
     self.set_prev_cdf(x, y)
   }
 
-  fn set_prev_cdf(&mut self, x: T, y: f32) -> &mut Self {
+  fn set_prev_cdf(&mut self, x: K, y: f64) -> &mut Self {
     self.prev_point = Coordinate { x, y };
     self
   }
-  fn set_upper_limit(&mut self, x: T, y: f32) -> &mut Self {
+  fn set_upper_limit(&mut self, x: K, y: f64) -> &mut Self {
     self.upper_limit = Coordinate { x, y };
     self
   }
-  fn set_lower_limit(&mut self, x: T, y: f32) -> &mut Self {
+  fn set_lower_limit(&mut self, x: K, y: f64) -> &mut Self {
     self.lower_limit = Coordinate { x, y };
     self
   }
 
-  fn add_key_to_spline(&mut self, coord: Coordinate) -> &mut Self {
+  fn add_key_to_spline(&mut self, coord: Coordinate<K>) -> &mut Self {
     self.spline_points.push(coord);
 
-    let curr_prefix = ((coord.x - self.min) >> self.shift_bits) as usize;
+    let curr_prefix = (coord.x.offset(self.min) >> self.shift_bits) as usize;
 
     if curr_prefix != self.prev_prefix {
       self.radix_table[self.prev_prefix + 1..=curr_prefix].fill(self.spline_points.len() - 1);
@@ -240,66 +353,547 @@ impl Builder {
 }
 
 #[derive(Debug, Default)]
-pub struct RadixSpline {
-  min: T,
-  max: T,
-  shift_bits: T,
+pub struct RadixSpline<K> {
+  min: K,
+  max: K,
+  shift_bits: u32,
 
-  max_error: f32,
+  max_error: f64,
   num_points: usize,
 
   radix_table: Vec<usize>,
-  spline_points: Vec<Coordinate>,
+  spline_points: Vec<Coordinate<K>>,
 }
 
-impl RadixSpline {
+// Shared query logic for `RadixSpline` (owned) and `RadixSplineView` (zero-copy borrowed):
+// both are just a `min`/`max`/`shift_bits`/`max_error`/`num_points` plus a radix table and
+// spline points, so every query - bound estimation, radix lookup, and the lookup/rank/bound
+// API built on top - is implemented once here as default methods and inherited by both.
+trait SplineData<K: Key> {
+  fn min(&self) -> K;
+  fn max(&self) -> K;
+  fn shift_bits(&self) -> u32;
+  fn max_error(&self) -> f64;
+  fn num_points(&self) -> usize;
+  fn radix_table(&self) -> &[usize];
+  fn spline_points(&self) -> &[Coordinate<K>];
+
   /// returns range in data[start..end] where key might be.
-  pub fn search_bound(&self, key: &T) -> (usize, usize) {
+  fn search_bound(&self, key: &K) -> (usize, usize) {
     let est = self.get_estimated_position(key);
-    let start = (est - self.max_error).max(0.);
-    let end = (est + self.max_error + 2.).min(self.num_points as f32);
+    let start = (est - self.max_error()).max(0.);
+    let end = (est + self.max_error() + 2.).min(self.num_points() as f64);
     (start as usize, end as usize)
   }
 
-  pub fn get_estimated_position(&self, key: &T) -> f32 {
-    if key <= &self.min {
+  fn get_estimated_position(&self, key: &K) -> f64 {
+    if key <= &self.min() {
       return 0.;
-    } else if key >= &self.max {
-      return (self.num_points - 1) as f32;
+    } else if key >= &self.max() {
+      return (self.num_points() - 1) as f64;
     }
     let idx = self.spline_segment(key);
     if idx == 0 {
       return 0.;
     }
-    let l = &self.spline_points[idx - 1];
-    let r = &self.spline_points[idx];
-    let slope = (r.y - l.y) / ((r.x - l.x) as f32);
+    let spline_points = self.spline_points();
+    let l = &spline_points[idx - 1];
+    let r = &spline_points[idx];
+    // Widen to f64 before the multiply-add: past ~2^24 points/key magnitude, f32 no longer
+    // has enough mantissa bits to represent consecutive positions exactly, which silently
+    // breaks the `max_error` guarantee.
+    let slope = (r.y - l.y) / (r.x.offset(l.x) as f64);
     debug_assert!(slope > 0.);
     debug_assert!(key >= &l.x);
 
-    slope * ((key - l.x) as f32) + l.y
+    slope * (key.offset(l.x) as f64) + l.y
   }
 
-  // gets the index of the end of the spline which contains key: T.
-  fn spline_segment(&self, key: &T) -> usize {
-    let prefix: usize = ((key - self.min) >> self.shift_bits) as usize;
-    debug_assert!(prefix + 1 < self.radix_table.len());
-    let begin = self.radix_table[prefix];
-    let end = self.radix_table[prefix + 1];
+  // gets the index of the end of the spline which contains key: K.
+  fn spline_segment(&self, key: &K) -> usize {
+    let prefix: usize = (key.offset(self.min()) >> self.shift_bits()) as usize;
+    let radix_table = self.radix_table();
+    debug_assert!(prefix + 1 < radix_table.len());
+    let begin = radix_table[prefix];
+    let end = radix_table[prefix + 1];
     debug_assert!(end >= begin);
+    let spline_points = self.spline_points();
     if end == begin {
       return begin;
     } else if end - begin < LINEAR_THRESH {
       return begin
-        + self.spline_points[begin..end]
+        + spline_points[begin..end]
           .iter()
           .position(|v| &v.x >= key)
           .unwrap();
     }
 
-    let lb = self.spline_points[begin..end].binary_search_by(|c| c.x.partial_cmp(key).unwrap());
+    let lb = spline_points[begin..end].binary_search_by(|c| c.x.partial_cmp(key).unwrap());
     match lb {
       Ok(i) | Err(i) => i,
     }
   }
+
+  /// Returns the index of `key` in `data`, or `None` if it isn't present.
+  fn lookup(&self, data: &[K], key: &K) -> Option<usize> {
+    let idx = self.lower_bound(data, key);
+    (idx < data.len() && &data[idx] == key).then_some(idx)
+  }
+
+  /// Returns the number of elements in `data` strictly less than `key`.
+  fn rank(&self, data: &[K], key: &K) -> usize {
+    self.lower_bound(data, key)
+  }
+
+  /// Returns the index of the first element in `data` that is `>= key`.
+  fn lower_bound(&self, data: &[K], key: &K) -> usize {
+    let (start, end) = self.search_bound(key);
+    search_in_bound(data, start, end, key)
+  }
+
+  /// Returns the index of the first element in `data` that is `> key`.
+  fn upper_bound(&self, data: &[K], key: &K) -> usize {
+    let mut idx = self.lower_bound(data, key);
+    while idx < data.len() && &data[idx] == key {
+      idx += 1;
+    }
+    idx
+  }
+
+  /// Returns the half-open range `data[start..end]` of elements in `[lo, hi)`.
+  fn range(&self, data: &[K], lo: K, hi: K) -> (usize, usize) {
+    (self.lower_bound(data, &lo), self.lower_bound(data, &hi))
+  }
+}
+
+// Finds the first index in `data[lo..hi]` whose element is `>= key`, using the same
+// linear-vs-binary heuristic as `spline_segment`. `lo`/`hi` are clamped to `data.len()` so an
+// out-of-domain `search_bound` degrades gracefully instead of panicking.
+//
+// `binary_search_by` only promises *some* index comparing equal, not the first one, so with
+// several duplicate keys in `data[lo..hi]` it could return a match partway through the run
+// instead of its start - `partition_point` does the same binary search but is specified to
+// return the partition boundary (the leftmost match here), which is what `lower_bound` needs.
+fn search_in_bound<K: Key>(data: &[K], lo: usize, hi: usize, key: &K) -> usize {
+  let lo = lo.min(data.len());
+  let hi = hi.min(data.len());
+  if hi - lo < LINEAR_THRESH {
+    lo
+      + data[lo..hi]
+        .iter()
+        .position(|v| v >= key)
+        .unwrap_or(hi - lo)
+  } else {
+    lo + data[lo..hi].partition_point(|v| v < key)
+  }
+}
+
+impl<K: Key> SplineData<K> for RadixSpline<K> {
+  fn min(&self) -> K {
+    self.min
+  }
+  fn max(&self) -> K {
+    self.max
+  }
+  fn shift_bits(&self) -> u32 {
+    self.shift_bits
+  }
+  fn max_error(&self) -> f64 {
+    self.max_error
+  }
+  fn num_points(&self) -> usize {
+    self.num_points
+  }
+  fn radix_table(&self) -> &[usize] {
+    &self.radix_table
+  }
+  fn spline_points(&self) -> &[Coordinate<K>] {
+    &self.spline_points
+  }
+}
+
+impl<K: Key> RadixSpline<K> {
+  /// In-memory footprint, in bytes, of the radix table and spline points backing this index.
+  pub fn size_bytes(&self) -> usize {
+    self.radix_table.len() * std::mem::size_of::<usize>()
+      + self.spline_points.len() * std::mem::size_of::<Coordinate<K>>()
+  }
+
+  /// returns range in data[start..end] where key might be.
+  pub fn search_bound(&self, key: &K) -> (usize, usize) {
+    SplineData::search_bound(self, key)
+  }
+
+  pub fn get_estimated_position(&self, key: &K) -> f64 {
+    SplineData::get_estimated_position(self, key)
+  }
+
+  /// Returns the index of `key` in `data`, or `None` if it isn't present.
+  pub fn lookup(&self, data: &[K], key: &K) -> Option<usize> {
+    SplineData::lookup(self, data, key)
+  }
+
+  /// Returns the number of elements in `data` strictly less than `key`.
+  pub fn rank(&self, data: &[K], key: &K) -> usize {
+    SplineData::rank(self, data, key)
+  }
+
+  /// Returns the index of the first element in `data` that is `>= key`.
+  pub fn lower_bound(&self, data: &[K], key: &K) -> usize {
+    SplineData::lower_bound(self, data, key)
+  }
+
+  /// Returns the index of the first element in `data` that is `> key`.
+  pub fn upper_bound(&self, data: &[K], key: &K) -> usize {
+    SplineData::upper_bound(self, data, key)
+  }
+
+  /// Returns the half-open range `data[start..end]` of elements in `[lo, hi)`.
+  pub fn range(&self, data: &[K], lo: K, hi: K) -> (usize, usize) {
+    SplineData::range(self, data, lo, hi)
+  }
+}
+
+/// Errors that can occur while reconstructing a [`RadixSpline`] from a byte buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromBytesError {
+  /// The buffer was too short to hold a valid header or data region.
+  Truncated,
+  /// The stored radix table length doesn't match what `min`/`max`/`shift_bits` imply.
+  RadixTableLenMismatch { expected: usize, found: usize },
+  /// `shift_bits` is `>= K::BYTES * 8`, so using it to shift a `K`-derived offset would
+  /// overflow.
+  InvalidShiftBits { shift_bits: u32, key_bits: u32 },
+  /// Spline point x-values were not monotonically non-decreasing.
+  NotMonotonic,
+  /// The buffer isn't aligned for a zero-copy reinterpretation; only [`RadixSpline::view`]
+  /// can hit this, since [`RadixSpline::from_bytes`] copies instead.
+  Misaligned,
+}
+
+impl std::fmt::Display for FromBytesError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Truncated => write!(f, "buffer is too short to hold a valid RadixSpline"),
+      Self::RadixTableLenMismatch { expected, found } => write!(
+        f,
+        "radix table length mismatch: expected {expected} entries, found {found}"
+      ),
+      Self::InvalidShiftBits {
+        shift_bits,
+        key_bits,
+      } => write!(
+        f,
+        "shift_bits {shift_bits} is out of range for a {key_bits}-bit key"
+      ),
+      Self::NotMonotonic => {
+        write!(f, "spline point x-values are not monotonically non-decreasing")
+      }
+      Self::Misaligned => write!(f, "buffer is not aligned for zero-copy reinterpretation"),
+    }
+  }
+}
+
+impl std::error::Error for FromBytesError {}
+
+// Layout written by `to_bytes`/read by `from_bytes`/`view`:
+//   header: min, max (K::BYTES each) | shift_bits: u32 | max_error: f64
+//           | num_points, radix_table_len, spline_points_len: u64 (all little-endian)
+//           | padding, up to the body's alignment
+//   body:   radix_table (native `usize` entries) | spline_points (native `Coordinate<K>` entries)
+//
+// The header is little-endian so it's portable to read on any machine; the two body regions
+// are left in native layout so `view` can reinterpret them in place with no allocation. That
+// means a serialized buffer is only safe to `view`/`from_bytes` back on a host with the same
+// pointer width and endianness that wrote it, and the padding means `view` additionally
+// requires the buffer itself to start at least that aligned (true for a `Vec<u8>` or an
+// mmap'd page).
+fn body_align<K>() -> usize {
+  std::mem::align_of::<usize>().max(std::mem::align_of::<Coordinate<K>>())
+}
+
+fn align_up(offset: usize, align: usize) -> usize {
+  offset.div_ceil(align) * align
+}
+
+struct Header<K> {
+  min: K,
+  max: K,
+  shift_bits: u32,
+  max_error: f64,
+  num_points: usize,
+  radix_table_len: usize,
+  spline_points_len: usize,
+  body_offset: usize,
+}
+
+fn take<'a>(bytes: &'a [u8], off: &mut usize, n: usize) -> Result<&'a [u8], FromBytesError> {
+  let end = off.checked_add(n).ok_or(FromBytesError::Truncated)?;
+  let s = bytes.get(*off..end).ok_or(FromBytesError::Truncated)?;
+  *off = end;
+  Ok(s)
+}
+
+fn parse_header<K: Key>(bytes: &[u8]) -> Result<Header<K>, FromBytesError> {
+  let mut off = 0;
+  let min = K::read_le(take(bytes, &mut off, K::BYTES)?);
+  let max = K::read_le(take(bytes, &mut off, K::BYTES)?);
+  let shift_bits = u32::from_le_bytes(take(bytes, &mut off, 4)?.try_into().unwrap());
+  let max_error = f64::from_le_bytes(take(bytes, &mut off, 8)?.try_into().unwrap());
+  let num_points = u64::from_le_bytes(take(bytes, &mut off, 8)?.try_into().unwrap()) as usize;
+  let radix_table_len =
+    u64::from_le_bytes(take(bytes, &mut off, 8)?.try_into().unwrap()) as usize;
+  let spline_points_len =
+    u64::from_le_bytes(take(bytes, &mut off, 8)?.try_into().unwrap()) as usize;
+
+  let key_bits = K::BYTES as u32 * 8;
+  if shift_bits >= key_bits {
+    return Err(FromBytesError::InvalidShiftBits {
+      shift_bits,
+      key_bits,
+    });
+  }
+
+  let expected_radix_len = 2 + (max.offset(min) >> shift_bits) as usize;
+  if radix_table_len != expected_radix_len {
+    return Err(FromBytesError::RadixTableLenMismatch {
+      expected: expected_radix_len,
+      found: radix_table_len,
+    });
+  }
+
+  Ok(Header {
+    min,
+    max,
+    shift_bits,
+    max_error,
+    num_points,
+    radix_table_len,
+    spline_points_len,
+    body_offset: align_up(off, body_align::<K>()),
+  })
+}
+
+fn check_monotonic<K: Key>(spline_points: &[Coordinate<K>]) -> Result<(), FromBytesError> {
+  if spline_points.windows(2).all(|w| w[0].x <= w[1].x) {
+    Ok(())
+  } else {
+    Err(FromBytesError::NotMonotonic)
+  }
+}
+
+// Reinterprets `s` as raw bytes in place, for writing out native-layout regions. Only sound
+// for types with no padding between/after their fields (e.g. `usize`) - a type with padding,
+// like `Coordinate<K>` for a narrow `K`, would read uninitialized bytes through this.
+fn bytes_of<T>(s: &[T]) -> &[u8] {
+  unsafe { std::slice::from_raw_parts(s.as_ptr() as *const u8, std::mem::size_of_val(s)) }
+}
+
+// Writes each `Coordinate<K>`'s bytes in the same native layout `view` reinterprets
+// (`#[repr(C)] { x: K, y: f64 }`), but field-by-field instead of transmuting the struct: for a
+// narrower `K` (e.g. u32/i32), `#[repr(C)]` pads the gap between `x` and `y` out to `y`'s
+// 8-byte alignment, and a `Coordinate { x, y }` literal never writes that padding - transmuting
+// the struct slice straight to bytes would read those uninitialized bytes into the output.
+fn coordinate_bytes_of<K>(points: &[Coordinate<K>]) -> Vec<u8> {
+  let x_size = std::mem::size_of::<K>();
+  let y_offset = std::mem::offset_of!(Coordinate<K>, y);
+  let stride = std::mem::size_of::<Coordinate<K>>();
+  let pad = y_offset - x_size;
+
+  let mut out = Vec::with_capacity(points.len() * stride);
+  for c in points {
+    let x_bytes = unsafe { std::slice::from_raw_parts((&c.x as *const K).cast::<u8>(), x_size) };
+    out.extend_from_slice(x_bytes);
+    out.extend(std::iter::repeat(0u8).take(pad));
+    out.extend_from_slice(&c.y.to_ne_bytes());
+  }
+  debug_assert_eq!(out.len(), points.len() * stride);
+  out
+}
+
+impl<K: Key> RadixSpline<K> {
+  /// Serializes this spline into a flat buffer, suitable for writing to disk and later
+  /// reloading via [`RadixSpline::from_bytes`] or, zero-copy, via [`RadixSpline::view`].
+  pub fn to_bytes(&self) -> Vec<u8> {
+    let mut out = Vec::with_capacity(
+      2 * K::BYTES
+        + 4
+        + 8
+        + 8 * 3
+        + self.radix_table.len() * std::mem::size_of::<usize>()
+        + self.spline_points.len() * std::mem::size_of::<Coordinate<K>>(),
+    );
+    self.min.write_le(&mut out);
+    self.max.write_le(&mut out);
+    out.extend_from_slice(&self.shift_bits.to_le_bytes());
+    out.extend_from_slice(&self.max_error.to_le_bytes());
+    out.extend_from_slice(&(self.num_points as u64).to_le_bytes());
+    out.extend_from_slice(&(self.radix_table.len() as u64).to_le_bytes());
+    out.extend_from_slice(&(self.spline_points.len() as u64).to_le_bytes());
+    out.resize(align_up(out.len(), body_align::<K>()), 0);
+    out.extend_from_slice(bytes_of(&self.radix_table));
+    out.extend_from_slice(&coordinate_bytes_of(&self.spline_points));
+    out
+  }
+
+  /// Reconstructs an owned `RadixSpline` from a buffer written by [`RadixSpline::to_bytes`].
+  ///
+  /// Copies the radix table and spline points out of `bytes`, so unlike [`RadixSpline::view`]
+  /// this doesn't require `bytes` to be aligned.
+  pub fn from_bytes(bytes: &[u8]) -> Result<Self, FromBytesError> {
+    let header = parse_header::<K>(bytes)?;
+    let body = bytes.get(header.body_offset..).ok_or(FromBytesError::Truncated)?;
+
+    let usize_size = std::mem::size_of::<usize>();
+    let radix_bytes_len = header.radix_table_len * usize_size;
+    let radix_bytes = body.get(..radix_bytes_len).ok_or(FromBytesError::Truncated)?;
+    let radix_table: Vec<usize> = radix_bytes
+      .chunks_exact(usize_size)
+      .map(|c| unsafe { (c.as_ptr() as *const usize).read_unaligned() })
+      .collect();
+
+    let point_size = std::mem::size_of::<Coordinate<K>>();
+    let point_bytes_len = header.spline_points_len * point_size;
+    let point_bytes = body
+      .get(radix_bytes_len..radix_bytes_len + point_bytes_len)
+      .ok_or(FromBytesError::Truncated)?;
+    let spline_points: Vec<Coordinate<K>> = point_bytes
+      .chunks_exact(point_size)
+      .map(|c| unsafe { (c.as_ptr() as *const Coordinate<K>).read_unaligned() })
+      .collect();
+    check_monotonic(&spline_points)?;
+
+    Ok(RadixSpline {
+      min: header.min,
+      max: header.max,
+      shift_bits: header.shift_bits,
+      max_error: header.max_error,
+      num_points: header.num_points,
+      radix_table,
+      spline_points,
+    })
+  }
+
+  /// Borrows a [`RadixSplineView`] directly over `bytes` (e.g. an mmap'd file) with no
+  /// allocation: the radix table and spline points are raw reinterpretations of `bytes`.
+  ///
+  /// `bytes` must have been written by [`RadixSpline::to_bytes`] on a host with the same
+  /// pointer width and endianness as this one.
+  pub fn view(bytes: &[u8]) -> Result<RadixSplineView<'_, K>, FromBytesError> {
+    let header = parse_header::<K>(bytes)?;
+    let body = bytes.get(header.body_offset..).ok_or(FromBytesError::Truncated)?;
+
+    let usize_size = std::mem::size_of::<usize>();
+    let radix_bytes_len = header.radix_table_len * usize_size;
+    let radix_bytes = body.get(..radix_bytes_len).ok_or(FromBytesError::Truncated)?;
+    if radix_bytes.as_ptr().align_offset(std::mem::align_of::<usize>()) != 0 {
+      return Err(FromBytesError::Misaligned);
+    }
+    let radix_table: &[usize] = unsafe {
+      std::slice::from_raw_parts(radix_bytes.as_ptr() as *const usize, header.radix_table_len)
+    };
+
+    let point_size = std::mem::size_of::<Coordinate<K>>();
+    let point_bytes_len = header.spline_points_len * point_size;
+    let point_bytes = body
+      .get(radix_bytes_len..radix_bytes_len + point_bytes_len)
+      .ok_or(FromBytesError::Truncated)?;
+    if point_bytes.as_ptr().align_offset(std::mem::align_of::<Coordinate<K>>()) != 0 {
+      return Err(FromBytesError::Misaligned);
+    }
+    let spline_points: &[Coordinate<K>] = unsafe {
+      std::slice::from_raw_parts(
+        point_bytes.as_ptr() as *const Coordinate<K>,
+        header.spline_points_len,
+      )
+    };
+    check_monotonic(spline_points)?;
+
+    Ok(RadixSplineView {
+      min: header.min,
+      max: header.max,
+      shift_bits: header.shift_bits,
+      max_error: header.max_error,
+      num_points: header.num_points,
+      radix_table,
+      spline_points,
+    })
+  }
+}
+
+/// A zero-copy, borrowed view over a [`RadixSpline`] serialized by [`RadixSpline::to_bytes`].
+///
+/// Backed directly by the buffer passed to [`RadixSpline::view`] (e.g. an mmap'd file), with
+/// no allocation on load.
+#[derive(Debug, Clone, Copy)]
+pub struct RadixSplineView<'a, K> {
+  min: K,
+  max: K,
+  shift_bits: u32,
+
+  max_error: f64,
+  num_points: usize,
+
+  radix_table: &'a [usize],
+  spline_points: &'a [Coordinate<K>],
+}
+
+impl<'a, K: Key> SplineData<K> for RadixSplineView<'a, K> {
+  fn min(&self) -> K {
+    self.min
+  }
+  fn max(&self) -> K {
+    self.max
+  }
+  fn shift_bits(&self) -> u32 {
+    self.shift_bits
+  }
+  fn max_error(&self) -> f64 {
+    self.max_error
+  }
+  fn num_points(&self) -> usize {
+    self.num_points
+  }
+  fn radix_table(&self) -> &[usize] {
+    self.radix_table
+  }
+  fn spline_points(&self) -> &[Coordinate<K>] {
+    self.spline_points
+  }
+}
+
+impl<'a, K: Key> RadixSplineView<'a, K> {
+  /// returns range in data[start..end] where key might be.
+  pub fn search_bound(&self, key: &K) -> (usize, usize) {
+    SplineData::search_bound(self, key)
+  }
+
+  pub fn get_estimated_position(&self, key: &K) -> f64 {
+    SplineData::get_estimated_position(self, key)
+  }
+
+  /// Returns the index of `key` in `data`, or `None` if it isn't present.
+  pub fn lookup(&self, data: &[K], key: &K) -> Option<usize> {
+    SplineData::lookup(self, data, key)
+  }
+
+  /// Returns the number of elements in `data` strictly less than `key`.
+  pub fn rank(&self, data: &[K], key: &K) -> usize {
+    SplineData::rank(self, data, key)
+  }
+
+  /// Returns the index of the first element in `data` that is `>= key`.
+  pub fn lower_bound(&self, data: &[K], key: &K) -> usize {
+    SplineData::lower_bound(self, data, key)
+  }
+
+  /// Returns the index of the first element in `data` that is `> key`.
+  pub fn upper_bound(&self, data: &[K], key: &K) -> usize {
+    SplineData::upper_bound(self, data, key)
+  }
+
+  /// Returns the half-open range `data[start..end]` of elements in `[lo, hi)`.
+  pub fn range(&self, data: &[K], lo: K, hi: K) -> (usize, usize) {
+    SplineData::range(self, data, lo, hi)
+  }
 }
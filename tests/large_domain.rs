@@ -0,0 +1,26 @@
+use radix_spline::Builder;
+
+// Regression test for the f32 -> f64 CDF/position migration: past ~2^24 points or key
+// magnitude, f32 no longer has enough mantissa bits to represent consecutive positions
+// exactly, which silently widened `search_bound` past `max_error`.
+#[test]
+fn dense_u64_domain_stays_within_error_bound() {
+  const N: u64 = 20_000_000;
+  const START: u64 = 1 << 40;
+
+  let vs: Vec<u64> = (0..N).map(|i| START + i * 3).collect();
+
+  let mut b = Builder::new(vs[0], *vs.last().unwrap());
+  for &v in &vs {
+    b.push(v);
+  }
+  let rs = b.build();
+
+  for &v in vs.iter().step_by(104_729) {
+    let (start, end) = rs.search_bound(&v);
+    assert!(
+      vs[start..end].contains(&v),
+      "key {v} not in returned bound [{start}, {end})"
+    );
+  }
+}
@@ -0,0 +1,68 @@
+use radix_spline::{Builder, RadixSpline};
+
+// Same generator as `tests/quickcheck.rs`: mostly-distinct values with one explicit duplicate
+// (`8128`), matching this crate's existing test data conventions.
+fn sample() -> (RadixSpline<u32>, Vec<u32>) {
+  let mut vs = (0..10000)
+    .map(|v| ((v as f32 * 377.98).fract().sin() + 1.) * 4500.)
+    .map(|v| v as u32)
+    .collect::<Vec<_>>();
+  vs.push(8128);
+  vs.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+
+  let mut b = Builder::new(vs[0], *vs.last().unwrap());
+  for &v in &vs {
+    b.push(v);
+  }
+  (b.build(), vs)
+}
+
+#[test]
+fn lookup_finds_present_keys_and_rejects_absent_ones() {
+  let (rs, vs) = sample();
+  let idx = rs.lookup(&vs, &8128).unwrap();
+  assert_eq!(vs[idx], 8128);
+  assert_eq!(rs.lookup(&vs, &u32::MAX), None);
+}
+
+#[test]
+fn rank_equals_lower_bound() {
+  let (rs, vs) = sample();
+  for &key in vs.iter().step_by(97) {
+    assert_eq!(rs.rank(&vs, &key), rs.lower_bound(&vs, &key));
+  }
+}
+
+#[test]
+fn lower_and_upper_bound_match_partition_point() {
+  let (rs, vs) = sample();
+  // `8128` was explicitly duplicated, so its lower/upper bound bracket is more than one wide.
+  for key in vs.iter().copied().step_by(97).chain(std::iter::once(8128u32)) {
+    assert_eq!(rs.lower_bound(&vs, &key), vs.partition_point(|&v| v < key));
+    assert_eq!(rs.upper_bound(&vs, &key), vs.partition_point(|&v| v <= key));
+  }
+}
+
+#[test]
+fn range_matches_a_plain_slice_partition() {
+  let (rs, vs) = sample();
+  let (lo, hi) = rs.range(&vs, 100, 200);
+  let expected_lo = vs.partition_point(|&v| v < 100);
+  let expected_hi = vs.partition_point(|&v| v < 200);
+  assert_eq!((lo, hi), (expected_lo, expected_hi));
+}
+
+#[test]
+fn view_query_methods_match_owned_spline() {
+  let (rs, vs) = sample();
+  let bytes = rs.to_bytes();
+  let view = RadixSpline::<u32>::view(&bytes).unwrap();
+
+  for &key in &[0u32, vs[10], vs[5000], 8128, u32::MAX] {
+    assert_eq!(view.lookup(&vs, &key), rs.lookup(&vs, &key));
+    assert_eq!(view.rank(&vs, &key), rs.rank(&vs, &key));
+    assert_eq!(view.lower_bound(&vs, &key), rs.lower_bound(&vs, &key));
+    assert_eq!(view.upper_bound(&vs, &key), rs.upper_bound(&vs, &key));
+  }
+  assert_eq!(view.range(&vs, 100, 200), rs.range(&vs, 100, 200));
+}
@@ -3,7 +3,7 @@ use radix_spline::{RadixSpline, Builder};
 #[macro_use(quickcheck)]
 extern crate quickcheck_macros;
 
-fn radix_spline() -> (RadixSpline, Vec<u32>) {
+fn radix_spline() -> (RadixSpline<u32>, Vec<u32>) {
   let mut vs = (0..10000)
     .map(|v| ((v as f32 * 377.98).fract().sin() + 1.) * 4500.)
     .map(|v| v as u32)
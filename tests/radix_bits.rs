@@ -0,0 +1,114 @@
+use radix_spline::Builder;
+
+fn dense_dataset() -> Vec<u32> {
+  let mut vs = (0..10000)
+    .map(|v| ((v as f32 * 377.98).fract().sin() + 1.) * 4500.)
+    .map(|v| v as u32)
+    .collect::<Vec<_>>();
+  vs.push(8128);
+  vs.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+  vs
+}
+
+#[test]
+fn with_radix_bits_changes_size_but_not_query_correctness() {
+  let vs = dense_dataset();
+
+  let mut narrow = Builder::new(vs[0], *vs.last().unwrap());
+  narrow.with_radix_bits(8);
+  for &v in &vs {
+    narrow.push(v);
+  }
+  let narrow = narrow.build();
+
+  let mut wide = Builder::new(vs[0], *vs.last().unwrap());
+  wide.with_radix_bits(18);
+  for &v in &vs {
+    wide.push(v);
+  }
+  let wide = wide.build();
+
+  assert!(
+    wide.size_bytes() > narrow.size_bytes(),
+    "a wider radix table should take up more space: wide={} narrow={}",
+    wide.size_bytes(),
+    narrow.size_bytes()
+  );
+
+  for &key in vs.iter().step_by(37) {
+    assert_eq!(narrow.lookup(&vs, &key), wide.lookup(&vs, &key));
+    assert!(narrow.lookup(&vs, &key).is_some());
+  }
+}
+
+#[test]
+fn auto_radix_picks_a_working_table_for_a_range_of_dataset_sizes() {
+  // `expected_keys` should roughly track the number of keys actually pushed, per
+  // `auto_radix`'s docs; a wildly mismatched estimate just yields an (intentionally) coarser
+  // or finer table, not incorrect results.
+  let vs = dense_dataset();
+  for &expected in &[vs.len() / 4, vs.len(), vs.len() * 4] {
+    let mut b = Builder::new(vs[0], *vs.last().unwrap());
+    b.auto_radix(expected);
+    for &v in &vs {
+      b.push(v);
+    }
+    let rs = b.build();
+    for &key in vs.iter().step_by(97) {
+      assert!(rs.lookup(&vs, &key).is_some());
+    }
+  }
+}
+
+#[test]
+fn with_radix_bits_at_key_bit_width_on_a_full_span_domain_does_not_panic() {
+  // Reproduces the chunk0-5 review report: `with_radix_bits` at exactly the key's bit width
+  // (here 32, for u32), on a domain spanning the key type's full range, must not overflow the
+  // radix table's size computation or attempt an unallocatable allocation.
+  let mut b = Builder::<u32>::new(0, u32::MAX);
+  b.with_radix_bits(32);
+  for i in 0..2000u32 {
+    b.push(i.wrapping_mul(2_147_483)); // spread across the full u32 range
+  }
+  let rs = b.build();
+  assert!(rs.size_bytes() > 0);
+
+  let mut b64 = Builder::<u64>::new(0, u64::MAX);
+  b64.with_radix_bits(64);
+  for i in 0..2000u64 {
+    b64.push(i * 4_611_686_018_427_387u64);
+  }
+  let rs64 = b64.build();
+  assert!(rs64.size_bytes() > 0);
+}
+
+fn noisy_dataset(n: u32, max: u32) -> Vec<u32> {
+  let mut vs = (0..n)
+    .map(|v| ((v as f32 * 377.98).fract().sin() + 1.) * (max as f32 / 2.))
+    .map(|v| v as u32)
+    .collect::<Vec<_>>();
+  vs.sort_unstable();
+  vs
+}
+
+#[test]
+fn size_bytes_grows_with_dataset_size() {
+  // A purely linear sequence compresses to a handful of spline points no matter how large it
+  // is, so use data with enough curvature that more points need more spline segments to stay
+  // within `max_error`.
+  let small_vs = noisy_dataset(1_000, 999);
+  let mut small = Builder::new(small_vs[0], *small_vs.last().unwrap());
+  for &v in &small_vs {
+    small.push(v);
+  }
+  let small = small.build();
+
+  let large_vs = noisy_dataset(100_000, 99_999);
+  let mut large = Builder::new(large_vs[0], *large_vs.last().unwrap());
+  for &v in &large_vs {
+    large.push(v);
+  }
+  let large = large.build();
+
+  assert!(large.size_bytes() > small.size_bytes());
+}
@@ -0,0 +1,145 @@
+use radix_spline::{Builder, FromBytesError, RadixSpline};
+
+// Header layout written by `to_bytes` (see the layout comment in `src/lib.rs`): for a u32 key,
+// `min`(4) | `max`(4) | `shift_bits`(4) | `max_error`(8) | `num_points`(8) |
+// `radix_table_len`(8) | `spline_points_len`(8), then padding up to the body's alignment.
+const SHIFT_BITS_OFF: usize = 8;
+const RADIX_TABLE_LEN_OFF: usize = 28;
+const HEADER_LEN: usize = 44;
+const BODY_ALIGN: usize = 8;
+
+fn sample_u32() -> (RadixSpline<u32>, Vec<u32>) {
+  let mut vs = (0..5000)
+    .map(|v| ((v as f32 * 377.98).fract().sin() + 1.) * 4500.)
+    .map(|v| v as u32)
+    .collect::<Vec<_>>();
+  vs.push(8128);
+  vs.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+
+  let mut b = Builder::new(vs[0], *vs.last().unwrap());
+  for &v in &vs {
+    b.push(v);
+  }
+  (b.build(), vs)
+}
+
+#[test]
+fn roundtrip_from_bytes_matches_original() {
+  let (rs, vs) = sample_u32();
+  let bytes = rs.to_bytes();
+  let rs2 = RadixSpline::<u32>::from_bytes(&bytes).unwrap();
+
+  for &v in vs.iter().step_by(17) {
+    assert_eq!(rs.search_bound(&v), rs2.search_bound(&v));
+  }
+}
+
+#[test]
+fn roundtrip_view_matches_original() {
+  let (rs, vs) = sample_u32();
+  let bytes = rs.to_bytes();
+  let view = RadixSpline::<u32>::view(&bytes).unwrap();
+
+  for &v in vs.iter().step_by(17) {
+    assert_eq!(rs.search_bound(&v), view.search_bound(&v));
+  }
+}
+
+#[test]
+fn to_bytes_is_deterministic() {
+  // Coordinate<u32> has padding between its 4-byte `x` and 8-byte `y` fields; if that padding
+  // leaked uninitialized memory into the output, two serializations of the same spline could
+  // differ.
+  let (rs, _) = sample_u32();
+  assert_eq!(rs.to_bytes(), rs.to_bytes());
+}
+
+#[test]
+fn truncated_buffer_is_rejected() {
+  let (rs, _) = sample_u32();
+  let bytes = rs.to_bytes();
+  for &len in &[0, 1, 4, bytes.len() / 2] {
+    assert_eq!(
+      RadixSpline::<u32>::from_bytes(&bytes[..len]).unwrap_err(),
+      FromBytesError::Truncated
+    );
+  }
+}
+
+#[test]
+fn radix_table_len_mismatch_is_rejected() {
+  let (rs, _) = sample_u32();
+  let mut bytes = rs.to_bytes();
+  let off = RADIX_TABLE_LEN_OFF;
+  let corrupted = u64::from_le_bytes(bytes[off..off + 8].try_into().unwrap()) + 1;
+  bytes[off..off + 8].copy_from_slice(&corrupted.to_le_bytes());
+
+  assert!(matches!(
+    RadixSpline::<u32>::from_bytes(&bytes).unwrap_err(),
+    FromBytesError::RadixTableLenMismatch { .. }
+  ));
+}
+
+#[test]
+fn invalid_shift_bits_is_rejected() {
+  let (rs, _) = sample_u32();
+  let mut bytes = rs.to_bytes();
+  let off = SHIFT_BITS_OFF;
+  bytes[off..off + 4].copy_from_slice(&1000u32.to_le_bytes());
+
+  assert_eq!(
+    RadixSpline::<u32>::from_bytes(&bytes).unwrap_err(),
+    FromBytesError::InvalidShiftBits {
+      shift_bits: 1000,
+      key_bits: 32
+    }
+  );
+  assert_eq!(
+    RadixSpline::<u32>::view(&bytes).unwrap_err(),
+    FromBytesError::InvalidShiftBits {
+      shift_bits: 1000,
+      key_bits: 32
+    }
+  );
+}
+
+#[test]
+fn not_monotonic_is_rejected() {
+  let (rs, _) = sample_u32();
+  let mut bytes = rs.to_bytes();
+
+  let radix_table_len =
+    u64::from_le_bytes(bytes[RADIX_TABLE_LEN_OFF..RADIX_TABLE_LEN_OFF + 8].try_into().unwrap())
+      as usize;
+  let body_offset = HEADER_LEN.div_ceil(BODY_ALIGN) * BODY_ALIGN;
+  let points_start = body_offset + radix_table_len * std::mem::size_of::<usize>();
+  let stride = std::mem::size_of::<u32>() + 4 /* x-y padding */ + 8; // Coordinate<u32>, 16 bytes
+
+  // Swap the first two spline points' `x` fields so the sequence is no longer
+  // monotonically non-decreasing.
+  let a = bytes[points_start..points_start + 4].to_vec();
+  let b = bytes[points_start + stride..points_start + stride + 4].to_vec();
+  bytes[points_start..points_start + 4].copy_from_slice(&b);
+  bytes[points_start + stride..points_start + stride + 4].copy_from_slice(&a);
+
+  assert_eq!(
+    RadixSpline::<u32>::from_bytes(&bytes).unwrap_err(),
+    FromBytesError::NotMonotonic
+  );
+}
+
+#[test]
+fn misaligned_view_buffer_is_rejected() {
+  let (rs, _) = sample_u32();
+  let bytes = rs.to_bytes();
+  // Prepend a single byte so the body, which `to_bytes` padded assuming the buffer itself
+  // starts maximally aligned, no longer does once we drop that leading byte below.
+  let mut padded = Vec::with_capacity(bytes.len() + 1);
+  padded.push(0u8);
+  padded.extend_from_slice(&bytes);
+
+  assert_eq!(
+    RadixSpline::<u32>::view(&padded[1..]).unwrap_err(),
+    FromBytesError::Misaligned
+  );
+}